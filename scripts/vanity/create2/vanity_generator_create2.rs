@@ -1,5 +1,6 @@
-use clap::{App, Arg};
-use ethers::core::types::{Address, H256};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use ethers::core::types::{Address, H256, U256};
+use ethers::signers::{LocalWallet, Signer};
 use ethers::utils::{hex, keccak256};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -8,12 +9,65 @@ use std::fs;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use rayon::prelude::*;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Semaphore};
 use std::time::{Duration, Instant};
 use chrono::prelude::*;
 use num_cpus;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use ctrlc;
+use log::{debug, error, info, warn};
+use reqwest::Client;
+
+// Minimal terminal logger for the `log` facade, in the spirit of czkawka's
+// `handsome_logger`: one line per record to stderr, filtered by level so
+// `--quiet` stays terse and `--verbose` (or `RUST_LOG`) surfaces per-phase
+// timings and other diagnostics.
+struct TerminalLogger;
+
+impl log::Log for TerminalLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static TERMINAL_LOGGER: TerminalLogger = TerminalLogger;
+
+// Install the terminal logger at `default_level`, unless `RUST_LOG` is set,
+// in which case it wins (matching `env_logger`'s precedence).
+fn init_logger(default_level: log::LevelFilter) {
+    let level = std::env::var("RUST_LOG")
+        .ok()
+        .and_then(|s| s.parse::<log::LevelFilter>().ok())
+        .unwrap_or(default_level);
+    log::set_max_level(level);
+    let _ = log::set_logger(&TERMINAL_LOGGER);
+}
+
+// Run `f`, logging its elapsed wall time at debug level in the spirit of
+// `fun_time`'s automatic phase timing. Only visible with --verbose or a
+// permissive RUST_LOG, so default runs stay quiet.
+fn time_phase<T>(phase: &str, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    debug!("{} took {}", phase, format_duration(start.elapsed()));
+    result
+}
+
+// Async counterpart of `time_phase`, for phases that await rather than block.
+async fn time_phase_async<T>(phase: &str, fut: impl std::future::Future<Output = T>) -> T {
+    let start = Instant::now();
+    let result = fut.await;
+    debug!("{} took {}", phase, format_duration(start.elapsed()));
+    result
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 enum PatternType {
@@ -27,19 +81,48 @@ enum PatternType {
     Regex,
 }
 
+// A raw patterns-file row, as loaded from JSON before being compiled into a
+// `Pattern` by `load_patterns`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct Pattern {
+struct PatternConfig {
     #[serde(rename = "type")]
     pattern_type: String,
     value: String,
 }
 
+// Whether a result's address has been confirmed empty via `--rpc-url`; stays
+// `Unchecked` when no RPC endpoint was configured for the run.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+enum DeploymentStatus {
+    #[serde(rename = "unchecked")]
+    Unchecked,
+    #[serde(rename = "confirmedEmpty")]
+    ConfirmedEmpty,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct VanityResult {
     salt: String,
     address: String,
     pattern: String,
     attempt: u64,
+    #[serde(rename = "deploymentStatus")]
+    deployment_status: DeploymentStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KeypairResult {
+    #[serde(rename = "privateKey")]
+    private_key: String,
+    address: String,
+    pattern: String,
+    attempt: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KeypairOutputResults {
+    timestamp: String,
+    results: Vec<KeypairResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,110 +143,1106 @@ struct OutputResults {
     results: Vec<VanityResult>,
 }
 
+// Progress checkpoint for a single worker's salt range, so a resumed run
+// restarts at `next_attempt` instead of retesting already-covered salts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RangeCheckpoint {
+    start: u64,
+    end: u64,
+    #[serde(rename = "nextAttempt")]
+    next_attempt: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SearchCheckpoint {
+    seed: u64,
+    deployer: String,
+    #[serde(rename = "codeHash")]
+    code_hash: String,
+    // Fingerprint of the configured patterns, so a checkpoint is only
+    // resumed against the search it was actually written for.
+    #[serde(rename = "patternFingerprint")]
+    pattern_fingerprint: String,
+    ranges: Vec<RangeCheckpoint>,
+}
+
+// CreateX factory contract address (same on every chain it's deployed to)
+const CREATEX_FACTORY_ADDRESS: &str = "0xba5Ed099633D3B313e4D5F7bdc1305d3c28ba5Ed";
+
+// keccak256 of CreateX's CREATE2 proxy runtime deployer bytecode
+// (0x67363d3d37363d34f03d5260086018f3), used as the init code hash for the
+// intermediate proxy that CREATE3 deploys through.
+const CREATEX_PROXY_INIT_CODE_HASH: &str =
+    "0x21c35dbe1b344a2488cf3321d6ce542f8e9f305544ff09e4993a62319a497c1f";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VanityMode {
+    Create2,
+    Create3,
+}
+
+impl FromStr for VanityMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create2" => Ok(VanityMode::Create2),
+            "create3" => Ok(VanityMode::Create3),
+            other => Err(format!("Unknown mode '{}', expected create2 or create3", other)),
+        }
+    }
+}
+
+// CreateX's salt-guarding regimes, selected by the salt's 20-byte prefix (the
+// deployer's address or the zero address) and its 21st byte (the redeploy
+// protection flag). `_guard` only reverts with `InvalidSalt` for a zero
+// prefix with a flag byte it doesn't recognize (anything above 0x01), which
+// `generate_guarded_salt` never produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SaltProtection {
+    // Salt prefix is the deployer, flag byte 0x00: guarded salt is
+    // keccak256(abi.encode(deployer, salt)). Only the deployer can use this
+    // salt, and the resulting address is the same on every chain.
+    None,
+    // Salt prefix is the deployer, flag byte 0x01: guarded salt is
+    // keccak256(abi.encode(deployer, chainId, salt)). Only the deployer can
+    // use this salt, and it deploys to a different address per chain.
+    CrossChain,
+    // Salt prefix is the zero address, flag byte 0x00: guarded salt is
+    // keccak256(abi.encode(salt)). Any address can deploy with this salt,
+    // and it lands at the same address on every chain.
+    Permissionless,
+}
+
+impl FromStr for SaltProtection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(SaltProtection::None),
+            "cross-chain" => Ok(SaltProtection::CrossChain),
+            "permissionless" => Ok(SaltProtection::Permissionless),
+            other => Err(format!(
+                "Unknown protection '{}', expected none, cross-chain, or permissionless",
+                other
+            )),
+        }
+    }
+}
+
 #[derive(Clone)]
 struct Create2VanityHelper {
     deployer_address: Address,
-    init_code_hash: H256,
+    // Only required for CREATE2; CREATE3 addresses don't depend on init code.
+    init_code_hash: Option<H256>,
+    mode: VanityMode,
+    protection: SaltProtection,
+    // Only required for `SaltProtection::CrossChain`, whose guarded salt
+    // binds to `block.chainid`.
+    chain_id: Option<u64>,
 }
 
 impl Create2VanityHelper {
-    fn new(deployer_address: Address, init_code_hash: H256) -> Self {
-        Self { deployer_address, init_code_hash }
+    fn new(
+        deployer_address: Address,
+        init_code_hash: Option<H256>,
+        mode: VanityMode,
+        protection: SaltProtection,
+        chain_id: Option<u64>,
+    ) -> Self {
+        Self { deployer_address, init_code_hash, mode, protection, chain_id }
+    }
+
+    // Compute the vanity address according to the configured mode
+    fn compute_address(&self, salt: H256) -> Address {
+        match self.mode {
+            VanityMode::Create2 => self.compute_create2_address(salt),
+            VanityMode::Create3 => self.compute_create3_address(salt),
+        }
     }
 
-    // Generate a guarded salt for CREATE2 deployment
+    // Generate a guarded salt, laying out the deployer/flag bytes according
+    // to the configured protection regime.
     fn generate_guarded_salt(&self, random_part: &[u8; 11]) -> H256 {
         let mut salt_bytes = [0u8; 32];
-        
-        // Copy deployer address to first 20 bytes
-        salt_bytes[0..20].copy_from_slice(&self.deployer_address.as_bytes());
-        
-        // Set the 21st byte to 0x00 (NO cross-chain protection)
-        salt_bytes[20] = 0x00;
-        
+
+        match self.protection {
+            SaltProtection::None => {
+                salt_bytes[0..20].copy_from_slice(&self.deployer_address.as_bytes());
+                salt_bytes[20] = 0x00;
+            }
+            SaltProtection::CrossChain => {
+                salt_bytes[0..20].copy_from_slice(&self.deployer_address.as_bytes());
+                salt_bytes[20] = 0x01;
+            }
+            SaltProtection::Permissionless => {
+                // First 20 bytes stay zero (the zero-address prefix); flag
+                // byte 0x00 is the fully-permissionless, same-address-on-
+                // every-chain case (0x01 would bind to `block.chainid`
+                // instead).
+                salt_bytes[20] = 0x00;
+            }
+        }
+
         // Copy the random part to the remaining 11 bytes
         salt_bytes[21..32].copy_from_slice(random_part);
-        
+
         H256::from(salt_bytes)
     }
 
-    // Calculate the actual salt used by CreateX contract
-    // This applies the CreateX special salt handling
-    fn calculate_create_x_salt(&self, salt: H256) -> H256 {
-        let salt_bytes = salt.as_bytes();
-        
-        // Check if first 20 bytes match deployer and 21st byte is 0x00
-        // This replicates the CreateX contract's salt guarding logic
-        let salt_deployer = Address::from_slice(&salt_bytes[0..20]);
-        if salt_deployer == self.deployer_address && salt_bytes[20] == 0 {
-            // Hash deployer with salt as per CreateX contract
-            let encoded = ethers::abi::encode(&[
-                ethers::abi::Token::Address(self.deployer_address),
-                ethers::abi::Token::FixedBytes(salt.as_bytes().to_vec())
-            ]);
-            
-            return H256::from_slice(&keccak256(&encoded));
+    // Calculate the actual salt used by CreateX contract
+    // This applies the CreateX special salt handling
+    fn calculate_create_x_salt(&self, salt: H256) -> H256 {
+        let salt_bytes = salt.as_bytes();
+        let salt_prefix = Address::from_slice(&salt_bytes[0..20]);
+
+        match self.protection {
+            SaltProtection::None if salt_prefix == self.deployer_address && salt_bytes[20] == 0x00 => {
+                let encoded = ethers::abi::encode(&[
+                    ethers::abi::Token::Address(self.deployer_address),
+                    ethers::abi::Token::FixedBytes(salt.as_bytes().to_vec()),
+                ]);
+                H256::from_slice(&keccak256(&encoded))
+            }
+            SaltProtection::CrossChain if salt_prefix == self.deployer_address && salt_bytes[20] == 0x01 => {
+                let chain_id = self.chain_id.expect("--chain-id is required for cross-chain protection");
+                let encoded = ethers::abi::encode(&[
+                    ethers::abi::Token::Address(self.deployer_address),
+                    ethers::abi::Token::Uint(U256::from(chain_id)),
+                    ethers::abi::Token::FixedBytes(salt.as_bytes().to_vec()),
+                ]);
+                H256::from_slice(&keccak256(&encoded))
+            }
+            SaltProtection::Permissionless if salt_prefix == Address::zero() && salt_bytes[20] == 0x00 => {
+                let encoded = ethers::abi::encode(&[ethers::abi::Token::FixedBytes(
+                    salt.as_bytes().to_vec(),
+                )]);
+                H256::from_slice(&keccak256(&encoded))
+            }
+            _ => salt,
+        }
+    }
+
+    // Compute CREATE2 address using the factory contract address
+    fn compute_create2_address(&self, salt: H256) -> Address {
+        // Apply the CreateX salt guarding logic
+        let guarded_salt = self.calculate_create_x_salt(salt);
+
+        // For CREATE2 address calculation, we need to use:
+        // 1. The CreateX factory address (hardcoded)
+        // 2. The guarded salt
+        // 3. The init code hash
+
+        // CreateX factory contract address
+        let factory_address = Address::from_str(CREATEX_FACTORY_ADDRESS).unwrap();
+
+        let init_code_hash = self
+            .init_code_hash
+            .expect("init_code_hash is required for CREATE2 address computation");
+
+        // BUILD THE CREATE2 INPUT: 0xff ++ factory_address ++ guarded_salt ++ keccak256(init_code)
+        let mut create2_input = Vec::with_capacity(1 + 20 + 32 + 32);
+        create2_input.push(0xff);
+        create2_input.extend_from_slice(factory_address.as_bytes());
+        create2_input.extend_from_slice(guarded_salt.as_bytes());
+        create2_input.extend_from_slice(init_code_hash.as_bytes());
+
+        // Hash it and take last 20 bytes for the address
+        let address_bytes = &keccak256(&create2_input)[12..];
+        Address::from_slice(address_bytes)
+    }
+
+    // Compute CREATE3 address. CreateX deploys a small CREATE2 proxy first
+    // (whose address only depends on the salt, not on the final init code),
+    // then that proxy deploys the real contract as its first transaction
+    // (nonce 1), so the final address is independent of the contract's
+    // bytecode entirely.
+    fn compute_create3_address(&self, salt: H256) -> Address {
+        let guarded_salt = self.calculate_create_x_salt(salt);
+
+        let factory_address = Address::from_str(CREATEX_FACTORY_ADDRESS).unwrap();
+        let proxy_init_code_hash = H256::from_str(CREATEX_PROXY_INIT_CODE_HASH).unwrap();
+
+        // Stage 1: proxy = keccak256(0xff ++ factory ++ guarded_salt ++ proxy_hash)[12..]
+        let mut proxy_input = Vec::with_capacity(1 + 20 + 32 + 32);
+        proxy_input.push(0xff);
+        proxy_input.extend_from_slice(factory_address.as_bytes());
+        proxy_input.extend_from_slice(guarded_salt.as_bytes());
+        proxy_input.extend_from_slice(proxy_init_code_hash.as_bytes());
+        let proxy_address = Address::from_slice(&keccak256(&proxy_input)[12..]);
+
+        // Stage 2: contract = keccak256(rlp([proxy, nonce=1]))[12..]
+        // rlp([proxy(20 bytes), 1]) = 0xd6 0x94 ++ proxy ++ 0x01 (23 bytes total)
+        let mut rlp_encoded = Vec::with_capacity(23);
+        rlp_encoded.push(0xd6);
+        rlp_encoded.push(0x94);
+        rlp_encoded.extend_from_slice(proxy_address.as_bytes());
+        rlp_encoded.push(0x01);
+
+        Address::from_slice(&keccak256(&rlp_encoded)[12..])
+    }
+}
+
+struct SearchRange {
+    start: u64,
+    end: u64,
+    // Attempt index to actually start iterating from; equal to `start`
+    // unless resuming from a checkpoint partway through the range.
+    resume_from: u64,
+    patterns: Vec<(String, Pattern)>,
+}
+
+// Process-wide worker thread count, resolved once from `--threads` (or the
+// available core count) and shared by every search mode, mirroring czkawka's
+// NUMBER_OF_THREADS/get_number_of_threads cell.
+static NUMBER_OF_THREADS: std::sync::OnceLock<usize> = std::sync::OnceLock::new();
+
+// Resolve and cache the worker thread count. The first caller's `requested`
+// value wins; later calls (even with a different `--threads`) just read back
+// the cached count, since a process only ever runs one search.
+fn get_number_of_threads(requested: Option<usize>) -> usize {
+    *NUMBER_OF_THREADS.get_or_init(|| requested.unwrap_or_else(num_cpus::get))
+}
+
+// A single vanity-match criterion, compiled once by `load_patterns` and
+// shared (read-only, cloned per worker) across every search thread.
+#[derive(Clone)]
+enum Pattern {
+    // Fixed hex prefix, including the "0x".
+    Prefix(String),
+    // Fixed hex suffix.
+    Suffix(String),
+    // Arbitrary regex over the full "0x"-prefixed 40-hex-char address.
+    Regex(Regex),
+    // "Gas golf" mode: rather than a yes/no match, `matches` gates on a
+    // minimum leading-zero-byte count, and `score` ranks what passes so
+    // callers can keep a bounded top-N instead of every hit.
+    ZeroBytes(u32),
+}
+
+impl Pattern {
+    fn matches(&self, address: &Address, checksum: bool) -> bool {
+        match self {
+            Pattern::Prefix(value) => Self::render(address, checksum).starts_with(value.as_str()),
+            Pattern::Suffix(value) => Self::render(address, checksum).ends_with(value.as_str()),
+            Pattern::Regex(re) => re.is_match(&Self::render(address, checksum)),
+            Pattern::ZeroBytes(min_leading) => leading_zero_bytes(address) >= *min_leading,
+        }
+    }
+
+    // The "gas golf" rank for a match; `None` for the plain yes/no modes.
+    fn score(&self, address: &Address) -> Option<u32> {
+        match self {
+            Pattern::ZeroBytes(_) => Some(zero_byte_score(address)),
+            _ => None,
+        }
+    }
+
+    fn render(address: &Address, checksum: bool) -> String {
+        if checksum {
+            ethers::utils::to_checksum(address, None)
+        } else {
+            format!("{:?}", address)
+        }
+    }
+}
+
+fn leading_zero_bytes(address: &Address) -> u32 {
+    address.as_bytes().iter().take_while(|byte| **byte == 0).count() as u32
+}
+
+fn total_zero_bytes(address: &Address) -> u32 {
+    address.as_bytes().iter().filter(|byte| **byte == 0).count() as u32
+}
+
+// "Gas golf" score: CREATE2 addresses with more leading zero bytes pack
+// smaller into calldata, so leading zeros are weighted far more heavily than
+// scattered ones.
+fn zero_byte_score(address: &Address) -> u32 {
+    leading_zero_bytes(address) * 100 + total_zero_bytes(address)
+}
+
+// How many hex nibbles a pattern pins down, for estimating its address-space
+// difficulty; `None` for a regex, whose selectivity can't be estimated
+// generically.
+fn matching_nibbles(pattern: &Pattern) -> Option<u32> {
+    match pattern {
+        Pattern::Prefix(value) => Some(value.trim_start_matches("0x").len() as u32),
+        Pattern::Suffix(value) => Some(value.len() as u32),
+        // A leading zero byte is two matching hex nibbles.
+        Pattern::ZeroBytes(min_leading) => Some(min_leading * 2),
+        Pattern::Regex(_) => None,
+    }
+}
+
+// Expected number of random attempts to find a matching address by chance:
+// 16^(matching nibbles).
+fn expected_attempts(pattern: &Pattern) -> Option<f64> {
+    matching_nibbles(pattern).map(|nibbles| 16f64.powi(nibbles as i32))
+}
+
+// A "gas golf" candidate ranked by `zero_byte_score`, kept by `TopResults`
+// instead of every address that passes the `Pattern::ZeroBytes` gate.
+#[derive(Clone)]
+struct ScoredResult {
+    score: u32,
+    result: VanityResult,
+}
+
+impl PartialEq for ScoredResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score
+    }
+}
+impl Eq for ScoredResult {}
+impl PartialOrd for ScoredResult {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for ScoredResult {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.score.cmp(&other.score)
+    }
+}
+
+// A bounded min-heap (via `Reverse`) of the best "gas golf" scores seen so
+// far, with the worst kept score on top. Once full, a
+// new candidate only displaces the current worst of the top-N, so the search
+// doesn't have to keep every address with a single leading zero byte.
+struct TopResults {
+    capacity: usize,
+    heap: std::collections::BinaryHeap<std::cmp::Reverse<ScoredResult>>,
+}
+
+impl TopResults {
+    fn new(capacity: usize) -> Self {
+        Self { capacity: capacity.max(1), heap: std::collections::BinaryHeap::new() }
+    }
+
+    fn offer(&mut self, candidate: ScoredResult) {
+        if self.heap.len() < self.capacity {
+            self.heap.push(std::cmp::Reverse(candidate));
+            return;
+        }
+        if let Some(std::cmp::Reverse(worst)) = self.heap.peek() {
+            if candidate.score > worst.score {
+                self.heap.pop();
+                self.heap.push(std::cmp::Reverse(candidate));
+            }
+        }
+    }
+
+    // Drain into the best-score-first results, emptying the heap.
+    fn drain_sorted(&mut self) -> Vec<VanityResult> {
+        let mut scored: Vec<ScoredResult> = self.heap.drain().map(|reverse| reverse.0).collect();
+        scored.sort_by(|a, b| b.score.cmp(&a.score));
+        scored.into_iter().map(|s| s.result).collect()
+    }
+}
+
+// A worker's outcome for a matched pattern: a plain match goes straight into
+// the results list, while a "gas golf" candidate only survives if it cracks
+// the bounded top-N.
+enum SearchHit {
+    Match(VanityResult),
+    Scored(ScoredResult),
+}
+
+// Helper function to save results
+fn save_results(output: &OutputResults, path: &str) -> std::io::Result<()> {
+    let output_json = serde_json::to_string_pretty(&output)?;
+    fs::write(path, output_json)
+}
+
+// Async counterpart of `save_results`, for call sites on the tokio runtime
+// (the collect task's periodic saves and the final save) so a large write
+// never blocks a worker thread. Synchronous call sites (run_brain, the
+// Ctrl+C handler) keep using the blocking version above.
+async fn save_results_async(output: &OutputResults, path: &str) -> std::io::Result<()> {
+    let output_json = serde_json::to_string_pretty(&output)?;
+    tokio::fs::write(path, output_json).await
+}
+
+// Cap on concurrent outbound `eth_getCode` requests, so a large batch of
+// matches (e.g. from a "gas golf" scoring run) doesn't flood the configured
+// RPC node, mirroring how a link checker bounds concurrent HTTP requests.
+const RPC_VERIFY_CONCURRENCY: usize = 8;
+
+// Ask the node whether `address` already has code deployed to it via
+// `eth_getCode`. Returns `true` when the address is empty (safe to use).
+async fn is_address_undeployed(
+    client: &Client,
+    rpc_url: &str,
+    address: &Address,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let request_body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_getCode",
+        "params": [format!("{:?}", address), "latest"],
+    });
+    let response: serde_json::Value = client.post(rpc_url).json(&request_body).send().await?.json().await?;
+    let code = response
+        .get("result")
+        .and_then(|value| value.as_str())
+        .ok_or("eth_getCode response missing \"result\"")?;
+    Ok(code == "0x")
+}
+
+// Post-filter `results` through `--rpc-url`, dropping addresses that already
+// have code deployed and marking the rest `ConfirmedEmpty`. Concurrency is
+// bounded by a semaphore rather than firing every request at once. Results
+// are left as-is (still `Unchecked`) if their RPC call errors, since a flaky
+// node shouldn't discard an otherwise-valid match.
+async fn filter_undeployed(client: &Client, rpc_url: &str, results: Vec<VanityResult>) -> Vec<VanityResult> {
+    let semaphore = Arc::new(Semaphore::new(RPC_VERIFY_CONCURRENCY));
+    let checks: Vec<_> = results
+        .into_iter()
+        .map(|result| {
+            let client = client.clone();
+            let rpc_url = rpc_url.to_string();
+            let semaphore = Arc::clone(&semaphore);
+            tokio::spawn(async move {
+                let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+                let address = match Address::from_str(&result.address) {
+                    Ok(address) => address,
+                    Err(_) => return Some(result),
+                };
+                match is_address_undeployed(&client, &rpc_url, &address).await {
+                    Ok(true) => Some(VanityResult { deployment_status: DeploymentStatus::ConfirmedEmpty, ..result }),
+                    Ok(false) => {
+                        info!("Dropping {} (already deployed)", result.address);
+                        None
+                    }
+                    Err(e) => {
+                        warn!("eth_getCode check failed for {}: {}", result.address, e);
+                        Some(result)
+                    }
+                }
+            })
+        })
+        .collect();
+
+    let mut filtered = Vec::with_capacity(checks.len());
+    for check in checks {
+        if let Some(result) = check.await.expect("RPC verification task panicked") {
+            filtered.push(result);
+        }
+    }
+    filtered
+}
+
+// Derive the 11-byte random part of a salt deterministically from a global
+// seed and the attempt counter, so a given seed always reproduces the same
+// stream of salts and an interrupted search can resume without retesting.
+fn derive_salt_random_part(seed: u64, attempt: u64) -> [u8; 11] {
+    let mut input = Vec::with_capacity(16);
+    input.extend_from_slice(&seed.to_le_bytes());
+    input.extend_from_slice(&attempt.to_le_bytes());
+    let hash = keccak256(&input);
+
+    let mut random_part = [0u8; 11];
+    random_part.copy_from_slice(&hash[0..11]);
+    random_part
+}
+
+// Key-stretch a human passphrase into the salt's 11-byte random part via
+// iterated keccak256, mirroring ethkey's brain-wallet derivation but for
+// CreateX salts instead of private keys. The same passphrase and iteration
+// count always reproduce the same salt, so a vanity address can be "remembered"
+// instead of saved.
+fn derive_salt_from_passphrase(passphrase: &str, iterations: u32) -> [u8; 11] {
+    let mut hash = keccak256(passphrase.as_bytes());
+    for _ in 1..iterations.max(1) {
+        hash = keccak256(&hash);
+    }
+
+    let mut random_part = [0u8; 11];
+    random_part.copy_from_slice(&hash[0..11]);
+    random_part
+}
+
+// Parse `--chain-id`, requiring it when `protection` is `CrossChain` since
+// that regime's guarded salt binds to `block.chainid`.
+fn parse_chain_id(
+    matches: &ArgMatches,
+    protection: SaltProtection,
+) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+    let chain_id = matches.value_of("chain-id").map(|s| s.parse::<u64>()).transpose()?;
+    if protection == SaltProtection::CrossChain && chain_id.is_none() {
+        return Err("--chain-id is required when --protection is cross-chain".into());
+    }
+    Ok(chain_id)
+}
+
+// Shared deployer/mode/protection/bytecode parsing for the brain/verify/recover
+// subcommands, which all need a `Create2VanityHelper` but don't run the
+// multi-threaded search loop that main()'s top-level arm does.
+fn build_vanity_helper(
+    matches: &ArgMatches,
+) -> Result<(Create2VanityHelper, Address, Option<H256>), Box<dyn std::error::Error>> {
+    let deployer_address_str = matches.value_of("deployer").ok_or("--deployer is required")?;
+    let deployer_address = Address::from_str(deployer_address_str)?;
+
+    let mode = matches.value_of("mode").unwrap_or("create2").parse::<VanityMode>()?;
+    let protection = matches
+        .value_of("protection")
+        .unwrap_or("none")
+        .parse::<SaltProtection>()?;
+    let chain_id = parse_chain_id(matches, protection)?;
+
+    let init_code_hash: Option<H256> = match mode {
+        VanityMode::Create2 => {
+            let bytecode_file_path = matches
+                .value_of("bytecode-file")
+                .ok_or("--bytecode-file is required in create2 mode")?;
+            let bytecode_content = fs::read_to_string(bytecode_file_path)?;
+            let bytecode_data: BytecodeFile = serde_json::from_str(&bytecode_content)?;
+
+            let hash = if !bytecode_data.bytecode_hash.is_empty() {
+                H256::from_str(&bytecode_data.bytecode_hash)?
+            } else {
+                let init_code = hex::decode(bytecode_data.bytecode.trim_start_matches("0x"))?;
+                H256::from_slice(&keccak256(&init_code))
+            };
+            Some(hash)
+        }
+        VanityMode::Create3 => None,
+    };
+
+    let helper = Create2VanityHelper::new(deployer_address, init_code_hash, mode, protection, chain_id);
+    Ok((helper, deployer_address, init_code_hash))
+}
+
+// Flip the case of a single ASCII letter; non-letters pass through unchanged.
+fn flip_case(c: char) -> char {
+    if c.is_uppercase() {
+        c.to_ascii_lowercase()
+    } else if c.is_lowercase() {
+        c.to_ascii_uppercase()
+    } else {
+        c
+    }
+}
+
+// Generate small edit-distance variations of an approximate passphrase:
+// whole-phrase case changes, a single flipped character, a trailing digit, or
+// one adjacent-character transposition (a common typo). Mirrors ethkey's
+// brain_recover, which does the same kind of fuzzing over a brain-wallet seed
+// phrase.
+fn generate_passphrase_variants(passphrase: &str) -> Vec<String> {
+    let mut variants = vec![passphrase.to_string(), passphrase.to_uppercase(), passphrase.to_lowercase()];
+
+    for i in 0..passphrase.chars().count() {
+        let flipped: String = passphrase
+            .chars()
+            .enumerate()
+            .map(|(j, ch)| if i == j { flip_case(ch) } else { ch })
+            .collect();
+        variants.push(flipped);
+    }
+
+    for digit in 0..10 {
+        variants.push(format!("{}{}", passphrase, digit));
+    }
+
+    let chars: Vec<char> = passphrase.chars().collect();
+    for i in 0..chars.len().saturating_sub(1) {
+        let mut swapped = chars.clone();
+        swapped.swap(i, i + 1);
+        variants.push(swapped.into_iter().collect());
+    }
+
+    variants.sort();
+    variants.dedup();
+    variants
+}
+
+// Derive a salt from a passphrase and report the address it produces,
+// optionally checking it against a patterns file and saving it like a regular
+// search result.
+fn run_brain(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let (helper, deployer_address, init_code_hash) = build_vanity_helper(matches)?;
+    info!("Deployer address: {}", deployer_address);
+    info!("Init code hash: {}", format_code_hash(&init_code_hash));
+
+    let passphrase = matches.value_of("passphrase").ok_or("--passphrase is required")?;
+    let iterations = matches
+        .value_of("iterations")
+        .map(|s| s.parse::<u32>())
+        .transpose()?
+        .unwrap_or(100_000);
+
+    let random_part = derive_salt_from_passphrase(passphrase, iterations);
+    let salt = helper.generate_guarded_salt(&random_part);
+    let address = helper.compute_address(salt);
+
+    info!("Passphrase: \"{}\" ({} iterations)", passphrase, iterations);
+    info!("Salt: 0x{}", hex::encode(salt.as_bytes()));
+    info!("Address: {:?}", address);
+
+    if let Some(patterns_file_path) = matches.value_of("patterns-file") {
+        let checksum = matches.is_present("checksum");
+        let patterns = load_patterns(patterns_file_path, checksum)?;
+
+        match patterns.iter().find(|(_, pattern)| pattern.matches(&address, checksum)) {
+            Some((description, _)) => info!("Matches pattern: {}", description),
+            None => info!("Does not match any configured pattern"),
+        }
+    }
+
+    if let Some(output_path) = matches.value_of("output") {
+        let result = VanityResult {
+            salt: format!("0x{}", hex::encode(salt.as_bytes())),
+            address: format!("{:?}", address),
+            pattern: "brain: derived from passphrase".to_string(),
+            attempt: 0,
+            deployment_status: DeploymentStatus::Unchecked,
+        };
+        let output = OutputResults {
+            timestamp: Utc::now().to_rfc3339(),
+            deployer: format!("{:?}", deployer_address),
+            code_hash: format_code_hash(&init_code_hash),
+            results: vec![result],
+        };
+        save_results(&output, output_path)?;
+        info!("Saved result to {}", output_path);
+    }
+
+    Ok(())
+}
+
+// Recompute the CreateX-guarded address for a previously saved salt and
+// confirm it matches the claimed address, so a `VanityResult` produced on one
+// machine can be independently validated on another.
+fn run_verify(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let (helper, deployer_address, init_code_hash) = build_vanity_helper(matches)?;
+    info!("Deployer address: {}", deployer_address);
+    info!("Init code hash: {}", format_code_hash(&init_code_hash));
+
+    let salt_str = matches.value_of("salt").ok_or("--salt is required")?;
+    let claimed_address_str = matches.value_of("address").ok_or("--address is required")?;
+
+    let salt = H256::from_str(salt_str)?;
+    let claimed_address = Address::from_str(claimed_address_str)?;
+    let recomputed_address = helper.compute_address(salt);
+
+    if recomputed_address == claimed_address {
+        info!(
+            "OK: salt 0x{} recomputes to {:?}, matches claimed address",
+            hex::encode(salt.as_bytes()),
+            recomputed_address
+        );
+        Ok(())
+    } else {
+        error!(
+            "MISMATCH: salt 0x{} recomputes to {:?}, expected {:?}",
+            hex::encode(salt.as_bytes()),
+            recomputed_address,
+            claimed_address
+        );
+        Err("verification failed: recomputed address does not match claimed address".into())
+    }
+}
+
+// Given an approximate passphrase and a target address, search small
+// edit-distance variations of the phrase for one whose derived salt produces
+// the target address. Mirrors ethkey's brain_recover behavior for salts.
+fn run_recover(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let (helper, deployer_address, init_code_hash) = build_vanity_helper(matches)?;
+    info!("Deployer address: {}", deployer_address);
+    info!("Init code hash: {}", format_code_hash(&init_code_hash));
+
+    let passphrase = matches.value_of("passphrase").ok_or("--passphrase is required")?;
+    let target_address = Address::from_str(matches.value_of("target").ok_or("--target is required")?)?;
+    let iterations = matches
+        .value_of("iterations")
+        .map(|s| s.parse::<u32>())
+        .transpose()?
+        .unwrap_or(100_000);
+
+    let variants = generate_passphrase_variants(passphrase);
+    info!("Trying {} passphrase variants...", variants.len());
+
+    for variant in &variants {
+        let random_part = derive_salt_from_passphrase(variant, iterations);
+        let salt = helper.generate_guarded_salt(&random_part);
+        let address = helper.compute_address(salt);
+
+        if address == target_address {
+            info!("Recovered! Passphrase variant: \"{}\"", variant);
+            info!("Salt: 0x{}", hex::encode(salt.as_bytes()));
+            info!("Address: {:?}", address);
+            return Ok(());
+        }
+    }
+
+    info!("No matching passphrase variant found among {} candidates", variants.len());
+    Err("recovery failed: no passphrase variant produced the target address".into())
+}
+
+// Sidecar checkpoint path next to the output results file.
+fn checkpoint_path_for(output_file_path: &str) -> String {
+    format!("{}.checkpoint.json", output_file_path)
+}
+
+fn save_checkpoint(checkpoint: &SearchCheckpoint, path: &str) -> std::io::Result<()> {
+    let checkpoint_json = serde_json::to_string_pretty(checkpoint)?;
+    fs::write(path, checkpoint_json)
+}
+
+fn load_checkpoint(path: &str) -> Result<SearchCheckpoint, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+// Render the init code hash for the output file; CREATE3 mode has none.
+fn format_code_hash(init_code_hash: &Option<H256>) -> String {
+    match init_code_hash {
+        Some(hash) => format!("{:?}", hash),
+        None => "N/A (CREATE3)".to_string(),
+    }
+}
+
+// Fingerprint the configured patterns (by their human-readable descriptions)
+// so a checkpoint can be tied to the search it was written for; resuming
+// against a different pattern set would silently skip salts the new patterns
+// never got a chance to match.
+fn pattern_fingerprint(patterns: &[(String, Pattern)]) -> String {
+    patterns.iter().map(|(description, _)| description.as_str()).collect::<Vec<_>>().join("|")
+}
+
+// Load and compile the patterns file into (description, Pattern) pairs
+// shared across salt- and keypair-mining workers. When `checksum` is set,
+// prefix/suffix/regex/contains patterns are compiled case-sensitively so
+// they can target a specific EIP-55 mixed-case rendering instead of
+// matching any case.
+fn load_patterns(
+    patterns_file_path: &str,
+    checksum: bool,
+) -> Result<Vec<(String, Pattern)>, Box<dyn std::error::Error>> {
+    let patterns_content = fs::read_to_string(patterns_file_path)?;
+    let pattern_configs: Vec<PatternConfig> = serde_json::from_str(&patterns_content)?;
+    info!("Loaded {} patterns", pattern_configs.len());
+
+    let case_flag = if checksum { "" } else { "(?i)" };
+
+    Ok(pattern_configs
+        .iter()
+        .map(|config| {
+            let (description, pattern) = match config.pattern_type.as_str() {
+                "prefix" => {
+                    let value = if checksum { config.value.clone() } else { config.value.to_lowercase() };
+                    (format!("starts with {}", config.value), Pattern::Prefix(format!("0x{}", value)))
+                }
+                "suffix" => {
+                    let value = if checksum { config.value.clone() } else { config.value.to_lowercase() };
+                    (format!("ends with {}", config.value), Pattern::Suffix(value))
+                }
+                "contains" => {
+                    let regex = Regex::new(&format!("{}{}", case_flag, config.value)).unwrap();
+                    (format!("contains {}", config.value), Pattern::Regex(regex))
+                }
+                "zero-bytes" => {
+                    let min_leading = config.value.parse::<u32>().unwrap_or(1);
+                    (
+                        format!("gas golf (at least {} leading zero bytes)", min_leading),
+                        Pattern::ZeroBytes(min_leading),
+                    )
+                }
+                "regex" => {
+                    let regex = Regex::new(&format!("{}{}", case_flag, config.value)).unwrap();
+                    (format!("matches regex {}", config.value), Pattern::Regex(regex))
+                }
+                _ => {
+                    let regex = Regex::new(&format!("{}^0x{}", case_flag, config.value)).unwrap();
+                    (format!("starts with {}", config.value), Pattern::Regex(regex))
+                }
+            };
+
+            debug!("Pattern: {} ({})", description, config.value);
+            (description, pattern)
+        })
+        .collect::<Vec<_>>())
+}
+
+// Helper function to format duration as human-readable time
+fn format_duration(duration: Duration) -> String {
+    let total_seconds = duration.as_secs();
+    
+    if total_seconds < 60 {
+        return format!("{}s", total_seconds);
+    }
+    
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+    
+    if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, seconds)
+    } else {
+        format!("{}m {}s", minutes, seconds)
+    }
+}
+
+// Mine random secp256k1 keypairs whose address matches the configured
+// patterns. Reuses the same multi-threaded range-partitioning/regex-matching
+// engine as the salt search, swapping the CREATE2/CREATE3 address
+// computation for a random wallet generation + address derivation.
+async fn run_keypair_search(matches: &ArgMatches<'_>) -> Result<(), Box<dyn std::error::Error>> {
+    let patterns_file_path = matches.value_of("patterns-file").unwrap();
+    let patterns = load_patterns(patterns_file_path, false)?;
+
+    let max_attempts = matches
+        .value_of("attempts")
+        .unwrap()
+        .parse::<u64>()
+        .unwrap_or(1000000);
+    info!("Max attempts: {}", max_attempts);
+
+    let requested_threads = matches.value_of("threads").and_then(|s| s.parse::<usize>().ok());
+    let num_threads = get_number_of_threads(requested_threads);
+    info!("Using {} worker threads", num_threads);
+
+    let timestamp = Utc::now().format("%Y-%m-%d_%H%M%S").to_string();
+    let output_file_path = matches
+        .value_of("output")
+        .map(|path| {
+            let path = std::path::Path::new(path);
+            let parent = path.parent().unwrap_or(std::path::Path::new(""));
+            let stem = path.file_stem().unwrap_or_default().to_str().unwrap_or("vanity-keypair");
+            let ext = path.extension().unwrap_or_default().to_str().unwrap_or("json");
+            parent.join(format!("{}_{}.{}", stem, timestamp, ext)).to_str().unwrap().to_string()
+        })
+        .unwrap_or_else(|| format!("./output/vanity-keypair_{}.json", timestamp));
+
+    if let Some(parent) = std::path::Path::new(&output_file_path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let overall_pb = ProgressBar::new(max_attempts);
+    overall_pb.set_style(
+        ProgressStyle::default_bar()
+            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({percent}%) @ {per_sec} {msg}")
+            .expect("Failed to set progress bar style")
+            .progress_chars("#>-"),
+    );
+    overall_pb.set_message("Starting...");
+
+    let (tx, mut rx) = mpsc::channel::<KeypairResult>(1000);
+    let (progress_tx, mut progress_rx) = mpsc::channel(1000);
+
+    let chunk_size = max_attempts / num_threads as u64;
+    let mut ranges = Vec::new();
+    for i in 0..num_threads {
+        let start = i as u64 * chunk_size;
+        let end = if i == num_threads - 1 {
+            max_attempts
+        } else {
+            (i + 1) as u64 * chunk_size
+        };
+        ranges.push(SearchRange { start, end, resume_from: start, patterns: patterns.clone() });
+    }
+
+    let results: Arc<Mutex<Vec<KeypairResult>>> = Arc::new(Mutex::new(Vec::new()));
+    let results_clone = Arc::clone(&results);
+
+    // Set up ctrl-c handler so a multi-hour mine doesn't lose already-found
+    // private keys if it's interrupted before the final save below.
+    let running = Arc::new(Mutex::new(true));
+    let r = running.clone();
+
+    let overall_pb_ctrl_c = overall_pb.clone();
+    let overall_pb_for_completion = overall_pb.clone();
+    let results_for_interrupt = Arc::clone(&results);
+    let output_path_for_interrupt = output_file_path.clone();
+
+    ctrlc::set_handler(move || {
+        let mut running = r.lock().unwrap();
+        *running = false;
+        info!("\nStopping search (Ctrl+C)...");
+
+        let final_results = results_for_interrupt.lock().unwrap();
+        if !final_results.is_empty() {
+            info!("Saving {} results before exit...", final_results.len());
+
+            let output = KeypairOutputResults {
+                timestamp: Utc::now().to_rfc3339(),
+                results: final_results.clone(),
+            };
+            match serde_json::to_string_pretty(&output)
+                .map_err(|e| e.to_string())
+                .and_then(|json| fs::write(&output_path_for_interrupt, json).map_err(|e| e.to_string()))
+            {
+                Ok(()) => {
+                    let abs_path = match std::fs::canonicalize(&output_path_for_interrupt) {
+                        Ok(p) => p.to_string_lossy().into_owned(),
+                        Err(_) => output_path_for_interrupt.clone(),
+                    };
+                    info!("Results saved to file://{} (on exit)", abs_path);
+                }
+                Err(e) => error!("Error saving results on exit: {}", e),
+            }
+        } else {
+            info!("No results to save.");
+        }
+
+        overall_pb_ctrl_c.finish_with_message("Search interrupted");
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        info!("Exiting...");
+        std::process::exit(0);
+    })
+    .expect("Error setting Ctrl-C handler");
+
+    let handles: Vec<_> = ranges
+        .into_par_iter()
+        .enumerate()
+        .map(|(i, range)| {
+            let tx = tx.clone();
+            let progress_tx = progress_tx.clone();
+            let patterns = range.patterns.clone();
+
+            std::thread::spawn(move || {
+                let mut rng = rand::thread_rng();
+                let mut last_progress_update = 0;
+                let progress_update_interval = 50_000;
+
+                for attempt in range.start..range.end {
+                    let wallet = LocalWallet::new(&mut rng);
+                    let address = wallet.address();
+                    let address_str = format!("{:?}", address);
+
+                    if i == 0 && attempt == range.start {
+                        debug!("Starting search... Example address: {}", address_str);
+                    }
+
+                    for (description, pattern) in &patterns {
+                        if pattern.matches(&address, false) {
+                            let private_key = format!("0x{}", hex::encode(wallet.signer().to_bytes()));
+                            let result = KeypairResult {
+                                private_key,
+                                address: address_str.clone(),
+                                pattern: description.clone(),
+                                attempt,
+                            };
+
+                            if let Err(e) = tx.blocking_send(result) {
+                                warn!("Failed to send result: {}", e);
+                            }
+
+                            break;
+                        }
+                    }
+
+                    if attempt - range.start >= last_progress_update + progress_update_interval {
+                        let progress = attempt - range.start - last_progress_update;
+                        last_progress_update = attempt - range.start;
+
+                        if let Err(e) = progress_tx.blocking_send(progress) {
+                            warn!("Failed to send progress update: {}", e);
+                        }
+                    }
+                }
+
+                let final_progress = range.end - range.start - last_progress_update;
+                if final_progress > 0 {
+                    if let Err(e) = progress_tx.blocking_send(final_progress) {
+                        warn!("Failed to send final progress update: {}", e);
+                    }
+                }
+            })
+        })
+        .collect();
+
+    let autosave_output_path = output_file_path.clone();
+    let running_for_task = running.clone();
+
+    let collect_task = tokio::spawn(async move {
+        let mut last_save_time = Instant::now();
+        let save_interval = std::time::Duration::from_secs(30);
+
+        loop {
+            if !*running_for_task.lock().unwrap() {
+                break;
+            }
+
+            tokio::select! {
+                Some(result) = rx.recv() => {
+                    let mut results_vec = results_clone.lock().unwrap();
+                    results_vec.push(result);
+                    info!("Found {} matches so far", results_vec.len());
+
+                    // Save results periodically so an interrupted or crashed
+                    // run doesn't lose already-found private keys.
+                    if last_save_time.elapsed() >= save_interval && !results_vec.is_empty() {
+                        let output = KeypairOutputResults {
+                            timestamp: Utc::now().to_rfc3339(),
+                            results: results_vec.clone(),
+                        };
+                        let saved_count = results_vec.len();
+                        drop(results_vec);
+
+                        match serde_json::to_string_pretty(&output)
+                            .map_err(|e| e.to_string())
+                            .and_then(|json| fs::write(&autosave_output_path, json).map_err(|e| e.to_string()))
+                        {
+                            Ok(()) => {
+                                let abs_path = match std::fs::canonicalize(&autosave_output_path) {
+                                    Ok(p) => p.to_string_lossy().into_owned(),
+                                    Err(_) => autosave_output_path.clone(),
+                                };
+                                info!("Saved {} results to file://{} (auto-save)", saved_count, abs_path);
+                            }
+                            Err(e) => error!("Error saving intermediate results: {}", e),
+                        }
+
+                        last_save_time = Instant::now();
+                    }
+                },
+                Some(progress) = progress_rx.recv() => {
+                    overall_pb.inc(progress);
+                },
+                else => break,
+            }
         }
-        
-        // No special processing needed
-        salt
-    }
+    });
 
-    // Compute CREATE2 address using the factory contract address
-    fn compute_create2_address(&self, salt: H256) -> Address {
-        // Apply the CreateX salt guarding logic
-        let guarded_salt = self.calculate_create_x_salt(salt);
-        
-        // For CREATE2 address calculation, we need to use:
-        // 1. The CreateX factory address (hardcoded)
-        // 2. The guarded salt
-        // 3. The init code hash
-        
-        // CreateX factory contract address
-        let factory_address = Address::from_str("0xba5Ed099633D3B313e4D5F7bdc1305d3c28ba5Ed").unwrap();
-        
-        // BUILD THE CREATE2 INPUT: 0xff ++ factory_address ++ guarded_salt ++ keccak256(init_code)
-        let mut create2_input = Vec::with_capacity(1 + 20 + 32 + 32);
-        create2_input.push(0xff);
-        create2_input.extend_from_slice(factory_address.as_bytes());
-        create2_input.extend_from_slice(guarded_salt.as_bytes());
-        create2_input.extend_from_slice(self.init_code_hash.as_bytes());
-        
-        // Hash it and take last 20 bytes for the address
-        let address_bytes = &keccak256(&create2_input)[12..];
-        Address::from_slice(address_bytes)
+    for handle in handles {
+        handle.join().unwrap();
     }
-}
 
-struct SearchRange {
-    start: u64,
-    end: u64,
-    patterns: Vec<(String, Regex)>,
-}
+    overall_pb_for_completion.finish_with_message("Search completed");
 
-// Helper function to save results
-fn save_results(output: &OutputResults, path: &str) -> std::io::Result<()> {
-    let output_json = serde_json::to_string_pretty(&output)?;
-    fs::write(path, output_json)
-}
+    drop(tx);
+    drop(progress_tx);
 
-// Helper function to format duration as human-readable time
-fn format_duration(duration: Duration) -> String {
-    let total_seconds = duration.as_secs();
-    
-    if total_seconds < 60 {
-        return format!("{}s", total_seconds);
-    }
-    
-    let hours = total_seconds / 3600;
-    let minutes = (total_seconds % 3600) / 60;
-    let seconds = total_seconds % 60;
-    
-    if hours > 0 {
-        format!("{}h {}m {}s", hours, minutes, seconds)
+    let _ = tokio::time::timeout(std::time::Duration::from_secs(2), collect_task).await;
+
+    let final_results = results.lock().unwrap();
+    info!("\n------------ FINAL RESULTS ------------");
+    info!("Total matches found: {}", final_results.len());
+
+    if !final_results.is_empty() {
+        let output = KeypairOutputResults {
+            timestamp: Utc::now().to_rfc3339(),
+            results: final_results.clone(),
+        };
+        let output_json = serde_json::to_string_pretty(&output)?;
+        fs::write(&output_file_path, output_json)?;
+
+        let abs_path = match std::fs::canonicalize(&output_file_path) {
+            Ok(p) => p.to_string_lossy().into_owned(),
+            Err(_) => output_file_path.clone(),
+        };
+        info!("Final results saved to: file://{}", abs_path);
     } else {
-        format!("{}m {}s", minutes, seconds)
+        info!("No matches found after {} attempts", max_attempts);
     }
+
+    info!("\nSearch complete. Process finished.");
+    std::process::exit(0);
 }
 
 #[tokio::main]
@@ -177,16 +1256,41 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             Arg::with_name("deployer")
                 .long("deployer")
                 .value_name("ADDRESS")
-                .help("The address that will deploy the contracts")
-                .required(true)
+                .help("The address that will deploy the contracts (not used by the keypair subcommand)")
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("mode")
+                .long("mode")
+                .value_name("MODE")
+                .help("Vanity address mode: create2 (bytecode-dependent) or create3 (bytecode-independent)")
+                .possible_values(&["create2", "create3"])
+                .default_value("create2")
                 .takes_value(true),
         )
         .arg(
             Arg::with_name("bytecode-file")
                 .long("bytecode-file")
                 .value_name("FILE")
-                .help("Path to bytecode JSON file")
-                .required(true)
+                .help("Path to bytecode JSON file (required in create2 mode, ignored in create3 mode)")
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("protection")
+                .long("protection")
+                .value_name("PROTECTION")
+                .help("CreateX salt protection regime: none, cross-chain, or permissionless")
+                .possible_values(&["none", "cross-chain", "permissionless"])
+                .default_value("none")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("chain-id")
+                .long("chain-id")
+                .value_name("NUMBER")
+                .help("Chain ID to bind the guarded salt to; required when --protection is cross-chain")
                 .takes_value(true),
         )
         .arg(
@@ -197,6 +1301,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .required(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("checksum")
+                .long("checksum")
+                .help("Match patterns case-sensitively against the EIP-55 checksummed address instead of a lowercase one")
+                .takes_value(false),
+        )
         .arg(
             Arg::with_name("output")
                 .long("output")
@@ -219,60 +1329,391 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .help("Number of worker threads to use")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("seed")
+                .long("seed")
+                .value_name("NUMBER")
+                .help("Seed for the deterministic salt stream; omit to generate a random one (printed at startup so the run can be reproduced)")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("resume")
+                .long("resume")
+                .value_name("FILE")
+                .help("Resume an interrupted search from a checkpoint file written alongside a previous run's output")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("top-n")
+                .long("top-n")
+                .value_name("NUMBER")
+                .help("Size of the bounded best-results heap for a zero-bytes (\"gas golf\") pattern")
+                .default_value("10")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("rpc-url")
+                .long("rpc-url")
+                .value_name("URL")
+                .help("Ethereum JSON-RPC endpoint; when set, each result is checked with eth_getCode and dropped if the address is already deployed")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("verbose")
+                .long("verbose")
+                .short("v")
+                .help("Log per-phase timings and other diagnostics (overridden by RUST_LOG if set)")
+                .global(true)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("quiet")
+                .long("quiet")
+                .short("q")
+                .help("Only log warnings and errors (overridden by RUST_LOG if set)")
+                .global(true)
+                .takes_value(false),
+        )
+        .subcommand(
+            SubCommand::with_name("keypair")
+                .about("Mine a random secp256k1 keypair whose address matches the patterns, instead of a CREATE2/CREATE3 salt")
+                .arg(
+                    Arg::with_name("patterns-file")
+                        .long("patterns-file")
+                        .value_name("FILE")
+                        .help("JSON file containing patterns to search for")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Output file path to save results")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("attempts")
+                        .long("attempts")
+                        .value_name("NUMBER")
+                        .help("Maximum number of attempts")
+                        .default_value("1000000")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("threads")
+                        .long("threads")
+                        .value_name("NUMBER")
+                        .help("Number of worker threads to use")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("brain")
+                .about("Derive a salt deterministically from a passphrase instead of searching")
+                .arg(
+                    Arg::with_name("deployer")
+                        .long("deployer")
+                        .value_name("ADDRESS")
+                        .help("The address that will deploy the contracts")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("mode")
+                        .long("mode")
+                        .value_name("MODE")
+                        .help("Vanity address mode: create2 (bytecode-dependent) or create3 (bytecode-independent)")
+                        .possible_values(&["create2", "create3"])
+                        .default_value("create2")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("bytecode-file")
+                        .long("bytecode-file")
+                        .value_name("FILE")
+                        .help("Path to bytecode JSON file (required in create2 mode, ignored in create3 mode)")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("protection")
+                        .long("protection")
+                        .value_name("PROTECTION")
+                        .help("CreateX salt protection regime: none, cross-chain, or permissionless")
+                        .possible_values(&["none", "cross-chain", "permissionless"])
+                        .default_value("none")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("chain-id")
+                        .long("chain-id")
+                        .value_name("NUMBER")
+                        .help("Chain ID to bind the guarded salt to; required when --protection is cross-chain")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("passphrase")
+                        .long("passphrase")
+                        .value_name("PHRASE")
+                        .help("Passphrase the salt's random part is key-stretched from")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("iterations")
+                        .long("iterations")
+                        .value_name("NUMBER")
+                        .help("Number of keccak256 key-stretching rounds applied to the passphrase")
+                        .default_value("100000")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("patterns-file")
+                        .long("patterns-file")
+                        .value_name("FILE")
+                        .help("Optional JSON patterns file to check the derived address against")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("checksum")
+                        .long("checksum")
+                        .help("Match patterns case-sensitively against the EIP-55 checksummed address instead of a lowercase one")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Output file path to save the derived result")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("verify")
+                .about("Recompute the CreateX-guarded address for a saved salt and confirm it matches")
+                .arg(
+                    Arg::with_name("deployer")
+                        .long("deployer")
+                        .value_name("ADDRESS")
+                        .help("The address that will deploy the contracts")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("mode")
+                        .long("mode")
+                        .value_name("MODE")
+                        .help("Vanity address mode: create2 (bytecode-dependent) or create3 (bytecode-independent)")
+                        .possible_values(&["create2", "create3"])
+                        .default_value("create2")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("bytecode-file")
+                        .long("bytecode-file")
+                        .value_name("FILE")
+                        .help("Path to bytecode JSON file (required in create2 mode, ignored in create3 mode)")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("protection")
+                        .long("protection")
+                        .value_name("PROTECTION")
+                        .help("CreateX salt protection regime: none, cross-chain, or permissionless")
+                        .possible_values(&["none", "cross-chain", "permissionless"])
+                        .default_value("none")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("chain-id")
+                        .long("chain-id")
+                        .value_name("NUMBER")
+                        .help("Chain ID to bind the guarded salt to; required when --protection is cross-chain")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("salt")
+                        .long("salt")
+                        .value_name("HEX")
+                        .help("The saved VanityResult salt (32-byte hex, pre-guard)")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("address")
+                        .long("address")
+                        .value_name("ADDRESS")
+                        .help("The saved VanityResult's claimed address")
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("recover")
+                .about("Search small edit-distance variations of an approximate passphrase for one matching a target address")
+                .arg(
+                    Arg::with_name("deployer")
+                        .long("deployer")
+                        .value_name("ADDRESS")
+                        .help("The address that will deploy the contracts")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("mode")
+                        .long("mode")
+                        .value_name("MODE")
+                        .help("Vanity address mode: create2 (bytecode-dependent) or create3 (bytecode-independent)")
+                        .possible_values(&["create2", "create3"])
+                        .default_value("create2")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("bytecode-file")
+                        .long("bytecode-file")
+                        .value_name("FILE")
+                        .help("Path to bytecode JSON file (required in create2 mode, ignored in create3 mode)")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("protection")
+                        .long("protection")
+                        .value_name("PROTECTION")
+                        .help("CreateX salt protection regime: none, cross-chain, or permissionless")
+                        .possible_values(&["none", "cross-chain", "permissionless"])
+                        .default_value("none")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("chain-id")
+                        .long("chain-id")
+                        .value_name("NUMBER")
+                        .help("Chain ID to bind the guarded salt to; required when --protection is cross-chain")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("passphrase")
+                        .long("passphrase")
+                        .value_name("PHRASE")
+                        .help("Known-approximate passphrase to fuzz")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("target")
+                        .long("target")
+                        .value_name("ADDRESS")
+                        .help("Target address to recover a matching salt for")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("iterations")
+                        .long("iterations")
+                        .value_name("NUMBER")
+                        .help("Number of keccak256 key-stretching rounds applied to each passphrase variant")
+                        .default_value("100000")
+                        .takes_value(true),
+                ),
+        )
         .get_matches();
 
+    let default_log_level = if matches.is_present("verbose") {
+        log::LevelFilter::Debug
+    } else if matches.is_present("quiet") {
+        log::LevelFilter::Warn
+    } else {
+        log::LevelFilter::Info
+    };
+    init_logger(default_log_level);
+
+    if let Some(keypair_matches) = matches.subcommand_matches("keypair") {
+        return run_keypair_search(keypair_matches).await;
+    }
+    if let Some(brain_matches) = matches.subcommand_matches("brain") {
+        return run_brain(brain_matches);
+    }
+    if let Some(verify_matches) = matches.subcommand_matches("verify") {
+        return run_verify(verify_matches);
+    }
+    if let Some(recover_matches) = matches.subcommand_matches("recover") {
+        return run_recover(recover_matches);
+    }
+
     // Parse deployer address
-    let deployer_address_str = matches.value_of("deployer").unwrap().to_string();
+    let deployer_address_str = matches
+        .value_of("deployer")
+        .ok_or("--deployer is required")?
+        .to_string();
     let deployer_address = Address::from_str(&deployer_address_str)?;
-    println!("Deployer address: {}", deployer_address);
+    info!("Deployer address: {}", deployer_address);
 
-    // Load bytecode from file
-    let bytecode_file_path = matches.value_of("bytecode-file").unwrap();
-    let bytecode_content = fs::read_to_string(bytecode_file_path)?;
-    let bytecode_data: BytecodeFile = serde_json::from_str(&bytecode_content)?;
-    
-    println!("Contract name: {}", bytecode_data.contract_name);
-    println!("Bytecode loaded: {} bytes", bytecode_data.bytecode.len());
+    // Parse vanity mode
+    let mode = matches.value_of("mode").unwrap_or("create2").parse::<VanityMode>()?;
+    info!("Mode: {:?}", mode);
 
-    // Get init code hash (either from file or calculate)
-    let init_code_hash = if !bytecode_data.bytecode_hash.is_empty() {
-        H256::from_str(&bytecode_data.bytecode_hash)?
-    } else {
-        let init_code = hex::decode(bytecode_data.bytecode.trim_start_matches("0x"))?;
-        H256::from_slice(&keccak256(&init_code))
-    };
-    println!("Init code hash: {}", init_code_hash);
+    // Parse salt protection regime
+    let protection = matches
+        .value_of("protection")
+        .unwrap_or("none")
+        .parse::<SaltProtection>()?;
+    info!("Salt protection: {:?}", protection);
+    let chain_id = parse_chain_id(&matches, protection)?;
+
+    // Load bytecode from file (CREATE2 only; CREATE3 addresses don't depend on init code)
+    let init_code_hash: Option<H256> =
+        time_phase("init code hash computation", || -> Result<Option<H256>, Box<dyn std::error::Error>> {
+            match mode {
+                VanityMode::Create2 => {
+                    let bytecode_file_path = matches
+                        .value_of("bytecode-file")
+                        .ok_or("--bytecode-file is required in create2 mode")?;
+                    let bytecode_content = fs::read_to_string(bytecode_file_path)?;
+                    let bytecode_data: BytecodeFile = serde_json::from_str(&bytecode_content)?;
+
+                    info!("Contract name: {}", bytecode_data.contract_name);
+                    info!("Bytecode loaded: {} bytes", bytecode_data.bytecode.len());
+
+                    let hash = if !bytecode_data.bytecode_hash.is_empty() {
+                        H256::from_str(&bytecode_data.bytecode_hash)?
+                    } else {
+                        let init_code = hex::decode(bytecode_data.bytecode.trim_start_matches("0x"))?;
+                        H256::from_slice(&keccak256(&init_code))
+                    };
+                    info!("Init code hash: {}", hash);
+                    Ok(Some(hash))
+                }
+                VanityMode::Create3 => {
+                    info!("Init code hash: not required for CREATE3");
+                    Ok(None)
+                }
+            }
+        })?;
 
     // Load patterns
     let patterns_file_path = matches.value_of("patterns-file").unwrap();
-    let patterns_content = fs::read_to_string(patterns_file_path)?;
-    let patterns: Vec<Pattern> = serde_json::from_str(&patterns_content)?;
-    println!("Loaded {} patterns", patterns.len());
+    let checksum = matches.is_present("checksum");
+    info!("Checksum matching: {}", checksum);
+    let patterns = load_patterns(patterns_file_path, checksum)?;
 
-    // Prepare regex patterns
-    let regex_patterns = patterns
+    // Estimate the hardest configured pattern's address-space difficulty
+    // (16^matching_nibbles) so the ETA reflects whether the search is
+    // realistically findable, not just --attempts.
+    let target_attempts: Option<f64> = patterns
         .iter()
-        .map(|pattern| {
-            let regex = match pattern.pattern_type.as_str() {
-                "prefix" => Regex::new(&format!(r"(?i)^0x{}", pattern.value)).unwrap(),
-                "suffix" => Regex::new(&format!(r"(?i){}$", pattern.value)).unwrap(),
-                "contains" => Regex::new(&format!(r"(?i){}", pattern.value)).unwrap(),
-                "regex" => Regex::new(&format!(r"(?i){}", pattern.value)).unwrap(),
-                _ => Regex::new(&format!(r"(?i)^0x{}", pattern.value)).unwrap(),
-            };
-            
-            let description = match pattern.pattern_type.as_str() {
-                "prefix" => format!("starts with {}", pattern.value),
-                "suffix" => format!("ends with {}", pattern.value),
-                "contains" => format!("contains {}", pattern.value),
-                "regex" => format!("matches regex {}", pattern.value),
-                _ => format!("starts with {}", pattern.value),
-            };
-            
-            println!("Pattern: {} ({})", description, pattern.value);
-            (description, regex)
-        })
-        .collect::<Vec<_>>();
+        .filter_map(|(_, pattern)| expected_attempts(pattern))
+        .fold(None, |hardest: Option<f64>, estimate| Some(hardest.map_or(estimate, |h| h.max(estimate))));
+    if let Some(estimate) = target_attempts {
+        info!(
+            "Estimated attempts for the hardest pattern: ~{:.0} ({:.0} matching nibbles)",
+            estimate,
+            estimate.log(16.0).round()
+        );
+    }
+    let pattern_fingerprint_str = pattern_fingerprint(&patterns);
 
     // Parse number of attempts
     let max_attempts = matches
@@ -280,14 +1721,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .unwrap()
         .parse::<u64>()
         .unwrap_or(1000000);
-    println!("Max attempts: {}", max_attempts);
+    info!("Max attempts: {}", max_attempts);
 
     // Get number of threads
-    let num_threads = matches
-        .value_of("threads")
-        .map(|s| s.parse::<usize>().unwrap_or(num_cpus::get()))
-        .unwrap_or(num_cpus::get());
-    println!("Using {} worker threads", num_threads);
+    let requested_threads = matches.value_of("threads").and_then(|s| s.parse::<usize>().ok());
+    let num_threads = get_number_of_threads(requested_threads);
+    info!("Using {} worker threads", num_threads);
 
     // Get output file path and ensure output directory exists
     let timestamp = Utc::now().format("%Y-%m-%d_%H%M%S").to_string();
@@ -307,15 +1746,57 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Ensure output directory exists
     if let Some(parent) = std::path::Path::new(&output_file_path).parent() {
-        std::fs::create_dir_all(parent)?;
+        tokio::fs::create_dir_all(parent).await?;
     }
 
     // Create vanity helper
-    let create2_helper = Create2VanityHelper::new(deployer_address, init_code_hash);
+    let create2_helper = Create2VanityHelper::new(deployer_address, init_code_hash, mode, protection, chain_id);
+
+    // Resume from a checkpoint if requested; this also pins the seed so the
+    // resumed run reproduces the same salt stream as the original.
+    let resumed_checkpoint: Option<SearchCheckpoint> = match matches.value_of("resume") {
+        Some(path) => {
+            let checkpoint = load_checkpoint(path)?;
+            if checkpoint.deployer != deployer_address_str {
+                warn!(
+                    "Warning: checkpoint deployer ({}) does not match --deployer ({})",
+                    checkpoint.deployer, deployer_address_str
+                );
+            }
+            if checkpoint.code_hash != format_code_hash(&init_code_hash) {
+                warn!(
+                    "Warning: checkpoint code hash ({}) does not match the current bytecode ({})",
+                    checkpoint.code_hash,
+                    format_code_hash(&init_code_hash)
+                );
+            }
+            if checkpoint.pattern_fingerprint != pattern_fingerprint_str {
+                warn!(
+                    "Warning: checkpoint was written for a different pattern set; resumed salts may never have been tested against the current patterns"
+                );
+            }
+            info!("Resuming from checkpoint: {}", path);
+            Some(checkpoint)
+        }
+        None => None,
+    };
+
+    let seed = match &resumed_checkpoint {
+        Some(checkpoint) => checkpoint.seed,
+        None => matches
+            .value_of("seed")
+            .map(|s| s.parse::<u64>())
+            .transpose()?
+            .unwrap_or_else(rand::random::<u64>),
+    };
+    info!("Seed: {} (pass --seed {} to reproduce this run)", seed, seed);
+
+    // Checkpoint sidecar for this run, written next to the output file
+    let checkpoint_file_path = checkpoint_path_for(&output_file_path);
 
     // Create a multi-progress bar for monitoring
     let mp = MultiProgress::new();
-    
+
     // Create a progress bar for overall progress
     let overall_pb = mp.add(ProgressBar::new(max_attempts));
     overall_pb.set_style(
@@ -325,15 +1806,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .progress_chars("#>-")
     );
     overall_pb.set_message("Starting...");
-    
+
     // Clone for later use
     let overall_pb_for_completion = overall_pb.clone();
-    
+
     // Channel for workers to send results
-    let (tx, mut rx) = mpsc::channel(1000);
+    let (tx, mut rx) = mpsc::channel::<SearchHit>(1000);
     let (progress_tx, mut progress_rx) = mpsc::channel(1000);
+    let (checkpoint_tx, mut checkpoint_rx) = mpsc::channel::<(usize, u64)>(1000);
+
+    // Bounded top-N for any "gas golf" (zero-byte scoring) pattern; unused
+    // (and harmless) when no such pattern is configured.
+    let top_n = matches
+        .value_of("top-n")
+        .map(|s| s.parse::<usize>().unwrap_or(10))
+        .unwrap_or(10);
+    info!("Gas golf top-N: {}", top_n);
+    let top_results: Arc<Mutex<TopResults>> = Arc::new(Mutex::new(TopResults::new(top_n)));
+    let top_results_clone = Arc::clone(&top_results);
 
-    // Split the work into ranges
+    // Split the work into ranges, resuming each one from its checkpointed
+    // position (if any) instead of its partition start.
     let chunk_size = max_attempts / num_threads as u64;
     let mut ranges = Vec::new();
     for i in 0..num_threads {
@@ -343,69 +1836,107 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         } else {
             (i + 1) as u64 * chunk_size
         };
+        let resume_from = resumed_checkpoint
+            .as_ref()
+            .and_then(|checkpoint| checkpoint.ranges.get(i))
+            .map(|range_checkpoint| range_checkpoint.next_attempt.clamp(start, end))
+            .unwrap_or(start);
         ranges.push(SearchRange {
             start,
             end,
-            patterns: regex_patterns.clone(),
+            resume_from,
+            patterns: patterns.clone(),
         });
     }
 
+    // Snapshot of each range's (start, end) plus its initial resume position,
+    // kept around for writing checkpoints after `ranges` is consumed below.
+    let range_bounds: Vec<(u64, u64, u64)> =
+        ranges.iter().map(|r| (r.start, r.end, r.resume_from)).collect();
+    let range_progress: Arc<Mutex<Vec<u64>>> = Arc::new(Mutex::new(
+        range_bounds.iter().map(|(_, _, resume_from)| *resume_from).collect(),
+    ));
+    let range_progress_clone = Arc::clone(&range_progress);
+
     // Results collection
     let results = Arc::new(Mutex::new(Vec::new()));
     let results_clone = Arc::clone(&results);
 
     // Worker logic
-    let handles: Vec<_> = ranges
+    let handles: Vec<_> = time_phase("worker spawn", || ranges
         .into_par_iter()
         .enumerate()
         .map(|(i, range)| {
             let tx = tx.clone();
             let progress_tx = progress_tx.clone();
+            let checkpoint_tx = checkpoint_tx.clone();
             let helper = create2_helper.clone();
             let patterns = range.patterns.clone();
+            let checksum = checksum;
+            let seed = seed;
 
             std::thread::spawn(move || {
-                let mut rng = rand::thread_rng();
                 let mut local_results = Vec::new();
-                let mut last_progress_update = 0;
+                // Already-completed prefix of this range gets credited to
+                // progress immediately so resumed runs don't look like they
+                // start from scratch.
+                let mut last_progress_update = range.resume_from - range.start;
+                if last_progress_update > 0 {
+                    if let Err(e) = progress_tx.blocking_send(last_progress_update) {
+                        warn!("Failed to send resumed progress: {}", e);
+                    }
+                }
                 let progress_update_interval = 50_000; // Update every 50k attempts instead of 10k
 
-                for attempt in range.start..range.end {
-                    // Generate random salt part (11 bytes)
-                    let mut random_part = [0u8; 11];
-                    rand::Rng::fill(&mut rng, &mut random_part);
+                for attempt in range.resume_from..range.end {
+                    // Derive the salt's random part deterministically from
+                    // the seed and attempt counter so the stream is
+                    // reproducible and resumable without retesting salts.
+                    let random_part = derive_salt_random_part(seed, attempt);
 
                     // Generate guarded salt
                     let salt = helper.generate_guarded_salt(&random_part);
-                    
-                    // Compute CREATE2 address
-                    let address = helper.compute_create2_address(salt);
-                    let address_str = format!("{:?}", address);
+
+                    // Compute the vanity address for the configured mode
+                    let address = helper.compute_address(salt);
+                    let address_str = if checksum {
+                        ethers::utils::to_checksum(&address, None)
+                    } else {
+                        format!("{:?}", address)
+                    };
 
                     // Only print a single example address at startup
                     if i == 0 && attempt == range.start {
-                        println!("Starting search... Example address: {}", address_str);
+                        debug!("Starting search... Example address: {}", address_str);
                     }
 
                     // Check if address matches any pattern
                     for (description, pattern) in &patterns {
-                        if pattern.is_match(&address_str) {
+                        if pattern.matches(&address, checksum) {
                             let result = VanityResult {
                                 salt: format!("0x{}", hex::encode(salt.as_bytes())),
                                 address: address_str.clone(),
                                 pattern: description.clone(),
                                 attempt,
+                                deployment_status: DeploymentStatus::Unchecked,
                             };
                             local_results.push(result.clone());
-                            
+
                             // Don't print match immediately to reduce scrolling
                             // Instead, we'll consolidate and print in batches
-                            
-                            // Send result through channel
-                            if let Err(e) = tx.blocking_send(result) {
-                                eprintln!("Failed to send result: {}", e);
+
+                            // A "gas golf" pattern is ranked and kept in the
+                            // bounded top-N instead of every hit; other
+                            // patterns are plain matches.
+                            let hit = match pattern.score(&address) {
+                                Some(score) => SearchHit::Scored(ScoredResult { score, result }),
+                                None => SearchHit::Match(result),
+                            };
+
+                            if let Err(e) = tx.blocking_send(hit) {
+                                warn!("Failed to send result: {}", e);
                             }
-                            
+
                             break;
                         }
                     }
@@ -414,9 +1945,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     if attempt - range.start >= last_progress_update + progress_update_interval {
                         let progress = attempt - range.start - last_progress_update;
                         last_progress_update = attempt - range.start;
-                        
+
                         if let Err(e) = progress_tx.blocking_send(progress) {
-                            eprintln!("Failed to send progress update: {}", e);
+                            warn!("Failed to send progress update: {}", e);
+                        }
+                        if let Err(e) = checkpoint_tx.blocking_send((i, attempt + 1)) {
+                            warn!("Failed to send checkpoint update: {}", e);
                         }
                     }
                 }
@@ -425,17 +1959,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let final_progress = range.end - range.start - last_progress_update;
                 if final_progress > 0 {
                     if let Err(e) = progress_tx.blocking_send(final_progress) {
-                        eprintln!("Failed to send final progress update: {}", e);
+                        warn!("Failed to send final progress update: {}", e);
                     }
                 }
+                if let Err(e) = checkpoint_tx.blocking_send((i, range.end)) {
+                    warn!("Failed to send final checkpoint update: {}", e);
+                }
 
                 // Only print thread completion messages if matches were found
                 if !local_results.is_empty() && local_results.len() >= 5 {
-                    println!("Worker {} found {} matches", i + 1, local_results.len());
+                    debug!("Worker {} found {} matches", i + 1, local_results.len());
                 }
             })
         })
-        .collect();
+        .collect());
 
     // Spawn task to collect results and update progress
     let deployer_str = deployer_address_str.clone();
@@ -453,68 +1990,121 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let output_path_for_interrupt = output_file_path.clone();
     let deployer_str_for_interrupt = deployer_address_str.clone();
     let init_hash_for_interrupt = init_code_hash.clone();
-    
+
+    // Clone checkpoint state for saving on interrupt
+    let range_progress_for_interrupt = Arc::clone(&range_progress);
+    let range_bounds_for_interrupt = range_bounds.clone();
+    let checkpoint_path_for_interrupt = checkpoint_file_path.clone();
+    let seed_for_interrupt = seed;
+    let pattern_fingerprint_for_interrupt = pattern_fingerprint_str.clone();
+
     ctrlc::set_handler(move || {
         let mut running = r.lock().unwrap();
         *running = false;
-        println!("\nStopping search (Ctrl+C)...");
+        info!("\nStopping search (Ctrl+C)...");
         
         // Save results before exiting
         let final_results = results_for_interrupt.lock().unwrap();
         if !final_results.is_empty() {
-            println!("Saving {} results before exit...", final_results.len());
+            info!("Saving {} results before exit...", final_results.len());
             
             // Create output structure
             let output = OutputResults {
                 timestamp: Utc::now().to_rfc3339(),
                 deployer: deployer_str_for_interrupt.clone(),
-                code_hash: format!("{:?}", init_hash_for_interrupt),
+                code_hash: format_code_hash(&init_hash_for_interrupt),
                 results: final_results.clone(),
             };
             
             // Save to file
             if let Err(e) = save_results(&output, &output_path_for_interrupt) {
-                eprintln!("Error saving results on exit: {}", e);
+                error!("Error saving results on exit: {}", e);
             } else {
                 // Create absolute path for clickable link
                 let abs_path = match std::fs::canonicalize(&output_path_for_interrupt) {
                     Ok(p) => p.to_string_lossy().into_owned(),
                     Err(_) => output_path_for_interrupt.clone(),
                 };
-                println!("Results saved to file://{} (on exit)", abs_path);
+                info!("Results saved to file://{} (on exit)", abs_path);
             }
         } else {
-            println!("No results to save.");
+            info!("No results to save.");
         }
-        
+
+        // Save a checkpoint so the search can resume without retesting salts
+        let progress = range_progress_for_interrupt.lock().unwrap();
+        let checkpoint = SearchCheckpoint {
+            seed: seed_for_interrupt,
+            deployer: deployer_str_for_interrupt.clone(),
+            code_hash: format_code_hash(&init_hash_for_interrupt),
+            pattern_fingerprint: pattern_fingerprint_for_interrupt.clone(),
+            ranges: range_bounds_for_interrupt
+                .iter()
+                .zip(progress.iter())
+                .map(|((start, end, _), next_attempt)| RangeCheckpoint {
+                    start: *start,
+                    end: *end,
+                    next_attempt: *next_attempt,
+                })
+                .collect(),
+        };
+        if let Err(e) = save_checkpoint(&checkpoint, &checkpoint_path_for_interrupt) {
+            error!("Error saving checkpoint on exit: {}", e);
+        } else {
+            info!("Checkpoint saved to {} (resume with --resume)", checkpoint_path_for_interrupt);
+        }
+
         // Finish progress bar to stop rendering
         overall_pb_ctrl_c.finish_with_message("Search interrupted");
         
         // Allow a little time for cleanup then force exit
         std::thread::sleep(std::time::Duration::from_secs(1));
-        println!("Exiting...");
+        info!("Exiting...");
         std::process::exit(0);
     }).expect("Error setting Ctrl-C handler");
 
+    let range_bounds_for_task = range_bounds.clone();
+    let checkpoint_file_path_for_task = checkpoint_file_path.clone();
+    let pattern_fingerprint_for_task = pattern_fingerprint_str.clone();
+
     let collect_task = tokio::spawn(async move {
         let start_time = Instant::now();
         let mut _processed = 0; // Total processed attempts
         let mut _processed_since_last_update = 0; // Added underscore to fix warning
         let mut last_status_time = Instant::now();
         let mut last_save_time = Instant::now();
+        let mut last_checkpoint_time = Instant::now();
         let status_interval = std::time::Duration::from_secs(5); // Update status every 5 seconds
-        let save_interval = std::time::Duration::from_secs(30); // Save results every 30 seconds
+        let save_interval = std::time::Duration::from_secs(30); // Checkpoint every 30 seconds
+        // Throttle result persistence to at most one write per
+        // `result_save_interval` instead of gating it on `save_interval`, so
+        // matches reach disk soon after they arrive rather than up to 30s
+        // later. A write only happens when a match arrives: a match landing
+        // within this interval of the previous write is saved with the next
+        // match after it, or by the final save.
+        let result_save_interval = std::time::Duration::from_millis(250);
         let mut consolidated_matches = 0;
         let mut last_rate = 0.0; // Processing rate (attempts per second)
-        
+
         loop {
             tokio::select! {
-                Some(result) = rx.recv() => {
+                Some(hit) = rx.recv() => {
+                    // A "gas golf" candidate only survives if it cracks the
+                    // bounded top-N; everything else is a plain match.
+                    let result = match hit {
+                        SearchHit::Scored(scored) => {
+                            top_results_clone.lock().unwrap().offer(scored);
+                            consolidated_matches += 1;
+                            continue;
+                        }
+                        SearchHit::Match(result) => result,
+                    };
+
                     let mut results_vec = results_clone.lock().unwrap();
                     results_vec.push(result);
                     let matches_count = results_vec.len();
                     consolidated_matches += 1;
-                    
+
                     // Only print status update periodically, not for every match
                     if last_status_time.elapsed() >= status_interval {
                         let elapsed = start_time.elapsed();
@@ -522,18 +2112,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         
                         // Calculate processing rate and estimated time remaining
                         last_rate = _processed as f64 / elapsed_secs;
-                        let remaining_attempts = max_attempts as i64 - _processed as i64;
-                        
-                        if remaining_attempts > 0 && last_rate > 0.0 {
-                            let remaining_secs = remaining_attempts as f64 / last_rate;
+                        let eta_target = target_attempts.unwrap_or(max_attempts as f64);
+                        let remaining_attempts = eta_target - _processed as f64;
+
+                        if remaining_attempts > 0.0 && last_rate > 0.0 {
+                            let remaining_secs = remaining_attempts / last_rate;
                             let eta = format_duration(Duration::from_secs_f64(remaining_secs));
-                            
-                            println!("Found {} matches in {:?} ({} new) - {:.2}M attempts/s - ETA: {}",
-                                matches_count, elapsed, consolidated_matches, 
+
+                            info!("Found {} matches in {:?} ({} new) - {} attempts, {:.2}M salts/s - ETA: {}",
+                                matches_count, elapsed, consolidated_matches, _processed,
                                 last_rate / 1_000_000.0, eta);
                         } else {
-                            println!("Found {} matches in {:?} ({} new) - {:.2}M attempts/s", 
-                                matches_count, elapsed, consolidated_matches,
+                            info!("Found {} matches in {:?} ({} new) - {} attempts, {:.2}M salts/s",
+                                matches_count, elapsed, consolidated_matches, _processed,
                                 last_rate / 1_000_000.0);
                         }
                         
@@ -542,28 +2133,33 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         _processed_since_last_update = 0;
                     }
                     
-                    // Save results periodically based on time interval instead of match count
-                    if last_save_time.elapsed() >= save_interval && !results_vec.is_empty() {
+                    // Persist as matches arrive, throttled so a burst of
+                    // matches doesn't trigger a write per match.
+                    if last_save_time.elapsed() >= result_save_interval && !results_vec.is_empty() {
                         // Create output structure
                         let output = OutputResults {
                             timestamp: Utc::now().to_rfc3339(),
                             deployer: deployer_str.clone(),
-                            code_hash: format!("{:?}", init_hash),
+                            code_hash: format_code_hash(&init_hash),
                             results: results_vec.clone(),
                         };
-                        
-                        // Save to file
-                        if let Err(e) = save_results(&output, &async_output_path) {
-                            eprintln!("Error saving intermediate results: {}", e);
+                        let saved_count = results_vec.len();
+                        // Release the lock before the async write so the
+                        // mutex isn't held across an await point.
+                        drop(results_vec);
+
+                        // Save to file off the executor thread
+                        if let Err(e) = save_results_async(&output, &async_output_path).await {
+                            error!("Error saving intermediate results: {}", e);
                         } else {
                             // Create absolute path for clickable link
-                            let abs_path = match std::fs::canonicalize(&async_output_path) {
+                            let abs_path = match tokio::fs::canonicalize(&async_output_path).await {
                                 Ok(p) => p.to_string_lossy().into_owned(),
                                 Err(_) => async_output_path.clone(),
                             };
-                            println!("Saved {} results to file://{} (auto-save)", results_vec.len(), abs_path);
+                            info!("Saved {} results to file://{} (auto-save)", saved_count, abs_path);
                         }
-                        
+
                         last_save_time = Instant::now();
                     }
                 },
@@ -571,15 +2167,53 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     _processed += progress;
                     _processed_since_last_update += progress;
                     overall_pb.inc(progress);
-                    
-                    // Update progress bar message with ETA
+
+                    // Update progress bar message with live throughput and an
+                    // ETA derived from the hardest pattern's address-space
+                    // difficulty (falling back to --attempts if unknown).
+                    last_rate = _processed as f64 / start_time.elapsed().as_secs_f64();
                     if last_rate > 0.0 {
-                        let remaining_attempts = max_attempts as i64 - _processed as i64;
-                        if remaining_attempts > 0 {
-                            let remaining_secs = remaining_attempts as f64 / last_rate;
+                        let eta_target = target_attempts.unwrap_or(max_attempts as f64);
+                        let remaining_attempts = eta_target - _processed as f64;
+                        if remaining_attempts > 0.0 {
+                            let remaining_secs = remaining_attempts / last_rate;
                             let eta = format_duration(Duration::from_secs_f64(remaining_secs));
-                            overall_pb.set_message(format!("ETA: {}", eta));
+                            overall_pb.set_message(format!(
+                                "{:.2}M salts/s | {} attempts | ETA: {}",
+                                last_rate / 1_000_000.0, _processed, eta
+                            ));
+                        }
+                    }
+
+                    // Persist per-range progress periodically so an
+                    // interrupted run can resume without retesting salts
+                    if last_checkpoint_time.elapsed() >= save_interval {
+                        let progress_vec = range_progress_clone.lock().unwrap();
+                        let checkpoint = SearchCheckpoint {
+                            seed,
+                            deployer: deployer_str.clone(),
+                            code_hash: format_code_hash(&init_hash),
+                            pattern_fingerprint: pattern_fingerprint_for_task.clone(),
+                            ranges: range_bounds_for_task
+                                .iter()
+                                .zip(progress_vec.iter())
+                                .map(|((start, end, _), next_attempt)| RangeCheckpoint {
+                                    start: *start,
+                                    end: *end,
+                                    next_attempt: *next_attempt,
+                                })
+                                .collect(),
+                        };
+                        if let Err(e) = save_checkpoint(&checkpoint, &checkpoint_file_path_for_task) {
+                            error!("Error saving checkpoint: {}", e);
                         }
+                        last_checkpoint_time = Instant::now();
+                    }
+                },
+                Some((idx, next_attempt)) = checkpoint_rx.recv() => {
+                    let mut progress_vec = range_progress_clone.lock().unwrap();
+                    if let Some(slot) = progress_vec.get_mut(idx) {
+                        *slot = next_attempt;
                     }
                 },
                 else => {
@@ -597,75 +2231,130 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // Wait for all worker threads to complete
-    for handle in handles {
-        handle.join().unwrap();
-    }
+    time_phase("mining loop", || {
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    });
 
     // Complete progress bar
     overall_pb_for_completion.finish_with_message("Search completed");
-    
+
     // Close all channels to prevent further output
     drop(tx);
     drop(progress_tx);
+    drop(checkpoint_tx);
 
     // Wait for collect task to complete with a timeout
-    match tokio::time::timeout(std::time::Duration::from_secs(2), collect_task).await {
+    match time_phase_async(
+        "collect-task drain",
+        tokio::time::timeout(std::time::Duration::from_secs(2), collect_task),
+    )
+    .await
+    {
         Ok(result) => {
             if let Err(e) = result {
-                eprintln!("Error in collect task: {}", e);
+                error!("Error in collect task: {}", e);
             }
         },
         Err(_) => {
-            println!("Timed out waiting for collection. Moving to final results...");
+            info!("Timed out waiting for collection. Moving to final results...");
         }
     }
 
-    // Final save
-    let final_results = results.lock().unwrap();
-    
-    println!("\n------------ FINAL RESULTS ------------");
-    println!("Total matches found: {}", final_results.len());
-    
-    if !final_results.is_empty() {
-        // Create output structure
-        let output = OutputResults {
-            timestamp: Utc::now().to_rfc3339(),
-            deployer: deployer_address_str,
-            code_hash: format!("{:?}", init_code_hash),
-            results: final_results.clone(),
+    // Final checkpoint write, reflecting the fully completed (or
+    // interrupted-and-rejoined) state of every range
+    {
+        let progress_vec = range_progress.lock().unwrap();
+        let final_checkpoint = SearchCheckpoint {
+            seed,
+            deployer: deployer_address_str.clone(),
+            code_hash: format_code_hash(&init_code_hash),
+            pattern_fingerprint: pattern_fingerprint_str.clone(),
+            ranges: range_bounds
+                .iter()
+                .zip(progress_vec.iter())
+                .map(|((start, end, _), next_attempt)| RangeCheckpoint {
+                    start: *start,
+                    end: *end,
+                    next_attempt: *next_attempt,
+                })
+                .collect(),
         };
-        
-        // Save to file using original path
-        if let Err(e) = save_results(&output, &output_file_path) {
-            eprintln!("Error saving final results: {}", e);
-        } else {
-            // Create absolute path for clickable link
-            let abs_path = match std::fs::canonicalize(&output_file_path) {
-                Ok(p) => p.to_string_lossy().into_owned(),
-                Err(_) => output_file_path.clone(),
+        if let Err(e) = save_checkpoint(&final_checkpoint, &checkpoint_file_path) {
+            error!("Error saving final checkpoint: {}", e);
+        }
+    }
+
+    // Final save; merge in the bounded top-N "gas golf" results (if any)
+    // alongside the plain pattern matches.
+    let mut final_results = results.lock().unwrap().clone();
+    let top_n_results = top_results.lock().unwrap().drain_sorted();
+    if !top_n_results.is_empty() {
+        info!("Top {} gas golf results kept out of the best seen", top_n_results.len());
+        final_results.extend(top_n_results);
+    }
+
+    // Post-filter against a live node so `final_results` only ever contains
+    // addresses we've confirmed (or left unchecked) are actually free to use.
+    if let Some(rpc_url) = matches.value_of("rpc-url") {
+        let rpc_client = Client::new();
+        let before = final_results.len();
+        final_results = filter_undeployed(&rpc_client, rpc_url, final_results).await;
+        info!(
+            "RPC verification via {}: {} of {} candidates confirmed undeployed",
+            rpc_url,
+            final_results.len(),
+            before
+        );
+    }
+
+    info!("\n------------ FINAL RESULTS ------------");
+    info!("Total matches found: {}", final_results.len());
+
+    time_phase_async("final save", async {
+        if !final_results.is_empty() {
+            // Create output structure
+            let output = OutputResults {
+                timestamp: Utc::now().to_rfc3339(),
+                deployer: deployer_address_str,
+                code_hash: format_code_hash(&init_code_hash),
+                results: final_results.clone(),
             };
-            println!("Final results saved to: file://{}", abs_path);
-            
-            // Show only first 3 matches in summary to avoid scrolling
-            let display_count = std::cmp::min(3, final_results.len());
-            if display_count > 0 {
-                println!("\nSample matches:");
-                for i in 0..display_count {
-                    let result = &final_results[i];
-                    println!("{}. Address: {}", i+1, result.address);
-                }
-                
-                if final_results.len() > display_count {
-                    println!("... and {} more matches in the output file", 
-                        final_results.len() - display_count);
+
+            // Save to file using original path, off the executor thread
+            if let Err(e) = save_results_async(&output, &output_file_path).await {
+                error!("Error saving final results: {}", e);
+            } else {
+                // Create absolute path for clickable link
+                let abs_path = match tokio::fs::canonicalize(&output_file_path).await {
+                    Ok(p) => p.to_string_lossy().into_owned(),
+                    Err(_) => output_file_path.clone(),
+                };
+                info!("Final results saved to: file://{}", abs_path);
+
+                // Show only first 3 matches in summary to avoid scrolling
+                let display_count = std::cmp::min(3, final_results.len());
+                if display_count > 0 {
+                    info!("\nSample matches:");
+                    for i in 0..display_count {
+                        let result = &final_results[i];
+                        info!("{}. Address: {}", i+1, result.address);
+                    }
+
+                    if final_results.len() > display_count {
+                        info!("... and {} more matches in the output file",
+                            final_results.len() - display_count);
+                    }
                 }
             }
+        } else {
+            info!("No matches found after {} attempts", max_attempts);
         }
-    } else {
-        println!("No matches found after {} attempts", max_attempts);
-    }
-    
-    println!("\nSearch complete. Process finished.");
+    })
+    .await;
+
+    info!("\nSearch complete. Process finished.");
     
     // Force exit to ensure all threads terminate
     std::process::exit(0);